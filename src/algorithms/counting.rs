@@ -0,0 +1,47 @@
+////////////////////////////////////
+/** Counting elements in a slice */
+////////////////////////////////////
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/** Returns the number of elements equal to the target in O(n) time */
+pub fn count_occurrences<T: PartialEq>(data: &[T], target: &T) -> usize {
+    data.iter().filter(|e| *e == target).count()
+}
+
+/** Tallies each distinct element in a single O(n) pass, returning a map of
+element to count in a std HashMap; The map only contains elements that actually appear */
+pub fn frequencies<T: Hash + Eq + Clone>(data: &[T]) -> HashMap<T, usize> {
+    let mut counts = HashMap::new();
+    for e in data {
+        *counts.entry(e.clone()).or_insert(0) += 1;
+    }
+    counts
+}
+
+#[test]
+fn counting_test() {
+    let words = ["the", "cat", "sat", "on", "the", "mat", "the", "end"];
+
+    // Single-element counts, including a missing element
+    assert_eq!(count_occurrences(&words, &"the"), 3);
+    assert_eq!(count_occurrences(&words, &"cat"), 1);
+    assert_eq!(count_occurrences(&words, &"dog"), 0);
+
+    // The map agrees with the single-element counts
+    let counts = frequencies(&words);
+    assert_eq!(counts.len(), 6); // One entry per distinct word
+    assert_eq!(counts.get("the"), Some(&3));
+    assert_eq!(counts.get("mat"), Some(&1));
+    assert_eq!(counts.get("dog"), None);
+    for (word, &count) in &counts {
+        assert_eq!(count_occurrences(&words, word), count);
+    }
+    assert_eq!(counts.values().sum::<usize>(), words.len());
+
+    // Works for owned values too, and an empty slice yields an empty map
+    let counts = frequencies(&[1, 2, 2, 3, 3, 3]);
+    assert_eq!(counts[&3], 3);
+    assert!(frequencies::<u8>(&[]).is_empty());
+}
@@ -0,0 +1,328 @@
+//////////////////////////////////////////
+/** Directed, adjacency list-based graphs */
+//////////////////////////////////////////
+
+use crate::lists::queues::priority_queue::sorted_list::{PriorityQueue, SortedVecQueue};
+use crate::lists::queues::vec_circ_queue::CircularQueue;
+
+/** Represents a directed graph with vertices 0..n where each vertex
+stores the list of vertices its outgoing edges point to
+
+The Graph's public API contains the following functions:
+ - new(vertices: usize) -> Graph
+ - add_edge(&mut self, from: usize, to: usize)
+ - vertices(&self) -> usize
+ - neighbors(&self, vertex: usize) -> &[usize]
+ - bfs(&self, start: usize) -> Vec<usize>
+ - dfs(&self, start: usize) -> Vec<usize>
+See also topo_sort(graph: &Graph) -> Result<Vec<usize>, CycleError>
+*/
+pub struct Graph {
+    adjacency: Vec<Vec<usize>>,
+}
+impl Graph {
+    /** Creates a graph with a fixed number of vertices and no edges */
+    pub fn new(vertices: usize) -> Graph {
+        Graph {
+            adjacency: vec![Vec::new(); vertices],
+        }
+    }
+    /** Adds a directed edge in O(1) time; Add the reverse edge too for an
+    undirected graph. Panics if either vertex is out of bounds */
+    pub fn add_edge(&mut self, from: usize, to: usize) {
+        assert!(to < self.adjacency.len(), "Vertex {} is out of bounds", to);
+        self.adjacency[from].push(to);
+    }
+    /** Returns the number of vertices in the graph */
+    pub fn vertices(&self) -> usize {
+        self.adjacency.len()
+    }
+    /** Returns the vertices that a vertex's outgoing edges point to */
+    pub fn neighbors(&self, vertex: usize) -> &[usize] {
+        &self.adjacency[vertex]
+    }
+    /** Returns the vertices reachable from start in breadth-first order in O(V + E) time;
+    Vertices in other components are never visited */
+    pub fn bfs(&self, start: usize) -> Vec<usize> {
+        let mut order = Vec::new();
+        let mut visited = vec![false; self.vertices()];
+        // Each vertex is enqueued at most once, so the queue never fills
+        let mut queue: CircularQueue<usize> = CircularQueue::new(self.vertices());
+        visited[start] = true;
+        queue.enqueue(start).expect("Queue is full");
+        while let Some(vertex) = queue.dequeue() {
+            order.push(vertex);
+            for &next in self.neighbors(vertex) {
+                // Marks on enqueue so self-loops and repeat edges are skipped
+                if !visited[next] {
+                    visited[next] = true;
+                    queue.enqueue(next).expect("Queue is full");
+                }
+            }
+        }
+        order
+    }
+    /** Returns the vertices reachable from start in depth-first order in O(V + E) time;
+    Uses an explicit stack, but visits vertices in the same order as the recursive version */
+    pub fn dfs(&self, start: usize) -> Vec<usize> {
+        let mut order = Vec::new();
+        let mut visited = vec![false; self.vertices()];
+        let mut stack = vec![start];
+        while let Some(vertex) = stack.pop() {
+            // A vertex can be pushed more than once before it's visited
+            if visited[vertex] {
+                continue;
+            }
+            visited[vertex] = true;
+            order.push(vertex);
+            // Pushes in reverse so the first neighbor is explored first
+            for &next in self.neighbors(vertex).iter().rev() {
+                if !visited[next] {
+                    stack.push(next);
+                }
+            }
+        }
+        order
+    }
+}
+
+/** Represents a directed graph whose edges carry non-negative weights
+
+The WeightedGraph's public API contains the following functions:
+ - new(vertices: usize) -> WeightedGraph
+ - add_edge(&mut self, from: usize, to: usize, weight: u64)
+ - vertices(&self) -> usize
+ - neighbors(&self, vertex: usize) -> &[(usize, u64)]
+*/
+pub struct WeightedGraph {
+    adjacency: Vec<Vec<(usize, u64)>>,
+}
+impl WeightedGraph {
+    /** Creates a weighted graph with a fixed number of vertices and no edges */
+    pub fn new(vertices: usize) -> WeightedGraph {
+        WeightedGraph {
+            adjacency: vec![Vec::new(); vertices],
+        }
+    }
+    /** Adds a directed, weighted edge in O(1) time; Panics if either vertex is out of bounds */
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: u64) {
+        assert!(to < self.adjacency.len(), "Vertex {} is out of bounds", to);
+        self.adjacency[from].push((to, weight));
+    }
+    /** Returns the number of vertices in the graph */
+    pub fn vertices(&self) -> usize {
+        self.adjacency.len()
+    }
+    /** Returns (vertex, weight) pairs for a vertex's outgoing edges */
+    pub fn neighbors(&self, vertex: usize) -> &[(usize, u64)] {
+        &self.adjacency[vertex]
+    }
+}
+
+// The sorted-Vec priority queue can't find and lower an existing entry's key,
+// so this version pushes a new entry each time it finds a shorter path and
+// skips stale entries for vertices that have already been settled. With
+// O(n) enqueues the whole thing runs in O(E^2) time; A heap-based adaptable
+// priority queue would bring that down to O((V + E) log V).
+/** Returns the shortest distance from the source to each vertex using
+Dijkstra's algorithm, or None for vertices the source can't reach */
+pub fn dijkstra(graph: &WeightedGraph, source: usize) -> Vec<Option<u64>> {
+    let mut distances: Vec<Option<u64>> = vec![None; graph.vertices()];
+    let mut settled = vec![false; graph.vertices()];
+    // Keys are tentative distances and values are vertices
+    let mut queue: SortedVecQueue<u64, usize> = SortedVecQueue::new();
+    distances[source] = Some(0);
+    queue.enqueue(0, source).ok();
+    while let Some(vertex) = queue.dequeue() {
+        // Skips stale entries left behind by later relaxations
+        if settled[vertex] {
+            continue;
+        }
+        settled[vertex] = true;
+        let distance = distances[vertex].expect("Queued vertices have a distance");
+        // Relaxes each outgoing edge
+        for &(next, weight) in graph.neighbors(vertex) {
+            // A path too long to fit in a u64 can't be shorter than anything, so it's skipped
+            let Some(candidate) = distance.checked_add(weight) else {
+                continue;
+            };
+            if !settled[next] && distances[next].is_none_or(|d| candidate < d) {
+                distances[next] = Some(candidate);
+                queue.enqueue(candidate, next).ok();
+            }
+        }
+    }
+    distances
+}
+
+/** Returned by topo_sort() when the graph has a cycle; Lists the vertices that
+could never be ordered, which are the ones on a cycle or downstream of one */
+#[derive(Debug, PartialEq)]
+pub struct CycleError {
+    pub unordered: Vec<usize>,
+}
+impl std::fmt::Display for CycleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Graph has a cycle through some of {:?}", self.unordered)
+    }
+}
+impl std::error::Error for CycleError {}
+
+/** Returns the vertices in a topological order (every edge points from an earlier
+vertex to a later one) using Kahn's algorithm in O(V + E) time; Repeatedly takes a
+vertex with no remaining incoming edges and removes its outgoing edges. If vertices
+are left over once there are none to take, they must be held up by a cycle */
+pub fn topo_sort(graph: &Graph) -> Result<Vec<usize>, CycleError> {
+    // Counts each vertex's incoming edges
+    let mut in_degree = vec![0; graph.vertices()];
+    for vertex in 0..graph.vertices() {
+        for &next in graph.neighbors(vertex) {
+            in_degree[next] += 1;
+        }
+    }
+    // Seeds the frontier with every vertex that has no incoming edges;
+    // Each vertex is enqueued at most once, so the queue never fills
+    let mut frontier: CircularQueue<usize> = CircularQueue::new(graph.vertices());
+    for (vertex, &d) in in_degree.iter().enumerate() {
+        if d == 0 {
+            frontier.enqueue(vertex).expect("Queue is full");
+        }
+    }
+    let mut order = Vec::with_capacity(graph.vertices());
+    while let Some(vertex) = frontier.dequeue() {
+        order.push(vertex);
+        // "Removes" the vertex's outgoing edges, freeing up any vertex that hits zero
+        for &next in graph.neighbors(vertex) {
+            in_degree[next] -= 1;
+            if in_degree[next] == 0 {
+                frontier.enqueue(next).expect("Queue is full");
+            }
+        }
+    }
+    if order.len() == graph.vertices() {
+        Ok(order)
+    } else {
+        let unordered = (0..graph.vertices()).filter(|&v| in_degree[v] > 0).collect();
+        Err(CycleError { unordered })
+    }
+}
+
+#[test]
+fn traversal_test() {
+    // An undirected graph with a self-loop on 3 and a separate 6-7 component
+    //   0 - 1 - 3 (loop)
+    //   |   |
+    //   2 - 4 - 5    6 - 7
+    let mut g = Graph::new(8);
+    for (a, b) in [(0, 1), (0, 2), (1, 3), (1, 4), (2, 4), (4, 5), (6, 7)] {
+        g.add_edge(a, b);
+        g.add_edge(b, a);
+    }
+    g.add_edge(3, 3);
+
+    // Breadth-first visits by distance from the start
+    assert_eq!(g.bfs(0), vec![0, 1, 2, 3, 4, 5]);
+    // Depth-first follows each path as far as it goes before backtracking
+    assert_eq!(g.dfs(0), vec![0, 1, 3, 4, 2, 5]);
+
+    // Other components are only reachable from their own vertices
+    assert_eq!(g.bfs(6), vec![6, 7]);
+    assert_eq!(g.dfs(7), vec![7, 6]);
+
+    // Edges are directed unless added in both directions
+    let mut g = Graph::new(3);
+    g.add_edge(0, 1);
+    g.add_edge(1, 2);
+    assert_eq!(g.bfs(2), vec![2]);
+    assert_eq!(g.dfs(0), vec![0, 1, 2]);
+}
+
+#[test]
+fn dijkstra_test() {
+    // The direct edges from 0 are all longer than the detours
+    //      0 --7--> 1 --1--> 3
+    //      |        ^        |
+    //      2        1        3
+    //      v        |        v
+    //      2 --3--> 4 --9--> 5
+    let mut g = WeightedGraph::new(7);
+    g.add_edge(0, 1, 7);
+    g.add_edge(0, 2, 2);
+    g.add_edge(2, 4, 3);
+    g.add_edge(4, 1, 1);
+    g.add_edge(1, 3, 1);
+    g.add_edge(3, 5, 3);
+    g.add_edge(4, 5, 9);
+    // Vertex 6 only has an edge out, so nothing reaches it
+    g.add_edge(6, 0, 1);
+
+    let distances = dijkstra(&g, 0);
+    assert_eq!(
+        distances,
+        vec![Some(0), Some(6), Some(2), Some(7), Some(5), Some(10), None]
+    );
+
+    // Distances depend on the source, and edges are one-way
+    let distances = dijkstra(&g, 4);
+    assert_eq!(
+        distances,
+        vec![None, Some(1), None, Some(2), Some(0), Some(5), None]
+    );
+
+    // Weights near u64::MAX are valid; Paths that would overflow are skipped
+    // rather than panicking, so 2 is only reachable through the direct edge
+    let mut g = WeightedGraph::new(3);
+    g.add_edge(0, 1, u64::MAX - 1);
+    g.add_edge(1, 2, 5);
+    g.add_edge(0, 2, u64::MAX);
+    assert_eq!(
+        dijkstra(&g, 0),
+        vec![Some(0), Some(u64::MAX - 1), Some(u64::MAX)]
+    );
+}
+
+#[test]
+fn topo_sort_test() {
+    // Checks that every edge points forward in the order
+    fn assert_topological(g: &Graph, order: &[usize]) {
+        assert_eq!(order.len(), g.vertices());
+        let mut position = vec![0; g.vertices()];
+        for (i, &v) in order.iter().enumerate() {
+            position[v] = i;
+        }
+        for v in 0..g.vertices() {
+            for &next in g.neighbors(v) {
+                assert!(position[v] < position[next], "{} -> {} points backward", v, next);
+            }
+        }
+    }
+
+    // A small DAG with a disconnected vertex (5)
+    //  0 -> 1 -> 3
+    //  |         ^
+    //  v         |
+    //  2 ------> 4
+    let mut g = Graph::new(6);
+    g.add_edge(0, 1);
+    g.add_edge(0, 2);
+    g.add_edge(1, 3);
+    g.add_edge(2, 4);
+    g.add_edge(4, 3);
+    let order = topo_sort(&g).unwrap();
+    assert_topological(&g, &order);
+    assert_eq!(order, vec![0, 5, 1, 2, 4, 3]);
+
+    // An empty graph has an empty order
+    assert_eq!(topo_sort(&Graph::new(0)), Ok(vec![]));
+
+    // Adding 3 -> 2 closes the cycle 2 -> 4 -> 3 -> 2
+    g.add_edge(3, 2);
+    let err = topo_sort(&g).unwrap_err();
+    assert_eq!(err.unordered, vec![2, 3, 4]);
+
+    // A self-loop is a cycle too
+    let mut g = Graph::new(2);
+    g.add_edge(0, 1);
+    g.add_edge(1, 1);
+    assert_eq!(topo_sort(&g), Err(CycleError { unordered: vec![1] }));
+}
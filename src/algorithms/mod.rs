@@ -0,0 +1,8 @@
+pub mod counting;
+pub mod graph;
+pub mod searching;
+pub mod sorting;
+
+pub use counting::{count_occurrences, frequencies};
+pub use searching::binary_search_by_key;
+pub use sorting::radix_sort;
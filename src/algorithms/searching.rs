@@ -0,0 +1,55 @@
+//////////////////////////////
+/** Searching sorted slices */
+//////////////////////////////
+
+/** A keyed variant of the binary search that mirrors std's slice::binary_search_by_key;
+ * Takes a slice sorted by the key that F derives from each element and a target key.
+ * Returns Ok(index) of a match or Err(index) where the target could be inserted to
+ * keep the slice sorted, all in O(log n) time */
+pub fn binary_search_by_key<T, B: Ord, F: Fn(&T) -> B>(
+    slice: &[T],
+    target: &B,
+    key: F,
+) -> Result<usize, usize> {
+    // Uses a half-open [left, right) range so the bounds can't underflow
+    let mut left = 0;
+    let mut right = slice.len();
+    while left < right {
+        let mid = left + (right - left) / 2;
+        match key(&slice[mid]).cmp(target) {
+            std::cmp::Ordering::Equal => return Ok(mid),
+            std::cmp::Ordering::Greater => right = mid,
+            std::cmp::Ordering::Less => left = mid + 1,
+        }
+    }
+    Err(left)
+}
+
+#[test]
+fn binary_search_by_key_test() {
+    struct Player {
+        name: &'static str,
+        score: u32,
+    }
+    // Sorted by score, but Player itself isn't Ord
+    let players = [
+        Player { name: "Dingus", score: 12 },
+        Player { name: "Dangus", score: 23 },
+        Player { name: "Brain", score: 616 },
+        Player { name: "Peter", score: 1223 },
+        Player { name: "Remus", score: 8234 },
+    ];
+
+    // Present targets, including both ends
+    let found = binary_search_by_key(&players, &616, |p| p.score);
+    assert_eq!(found, Ok(2));
+    assert_eq!(players[found.unwrap()].name, "Brain");
+    assert_eq!(binary_search_by_key(&players, &12, |p| p.score), Ok(0));
+    assert_eq!(binary_search_by_key(&players, &8234, |p| p.score), Ok(4));
+
+    // Absent targets report where they would be inserted
+    assert_eq!(binary_search_by_key(&players, &1, |p| p.score), Err(0));
+    assert_eq!(binary_search_by_key(&players, &700, |p| p.score), Err(3));
+    assert_eq!(binary_search_by_key(&players, &9000, |p| p.score), Err(5));
+    assert_eq!(binary_search_by_key(&players[..0], &9000, |p| p.score), Err(0));
+}
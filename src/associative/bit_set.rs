@@ -0,0 +1,206 @@
+///////////////////////////////////////
+/** A bit set for dense integer keys */
+///////////////////////////////////////
+
+const WORD_BITS: usize = 64;
+
+/** Stores a set of usize values as bits in a Vec<u64>, where value n is bit
+n % 64 of word n / 64; Takes one bit per possible value instead of a whole
+entry per stored value, so it's compact when the values are dense and small.
+The word array grows as needed on insert, and set operations work a whole
+word (64 values) at a time.
+
+The BitSet's public API contains the following functions:
+ - new() -> BitSet
+ - insert(&mut self, n: usize) -> bool
+ - remove(&mut self, n: usize) -> bool
+ - contains(&self, n: usize) -> bool
+ - len(&self) -> usize
+ - is_empty(&self) -> bool
+ - iter(&self) -> impl Iterator<Item = usize>
+ - union(&self, other: &BitSet) -> BitSet
+ - intersection(&self, other: &BitSet) -> BitSet
+ - difference(&self, other: &BitSet) -> BitSet
+NOTE: insert(), remove(), and contains() run in O(1) time (amortized for insert),
+and the set operations run in O(w) time where w is the number of words */
+#[derive(Clone, Debug, Default)]
+pub struct BitSet {
+    words: Vec<u64>,
+    len: usize,
+}
+impl BitSet {
+    /** Creates an empty set without allocating */
+    pub fn new() -> BitSet {
+        BitSet {
+            words: Vec::new(),
+            len: 0,
+        }
+    }
+    /** Adds n to the set, growing the word array if needed;
+    Returns false if n was already present */
+    pub fn insert(&mut self, n: usize) -> bool {
+        let (word, mask) = Self::locate(n);
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        let was_present = self.words[word] & mask != 0;
+        self.words[word] |= mask;
+        if !was_present {
+            self.len += 1;
+        }
+        !was_present
+    }
+    /** Removes n from the set; Returns false if n wasn't present */
+    pub fn remove(&mut self, n: usize) -> bool {
+        if !self.contains(n) {
+            return false;
+        }
+        let (word, mask) = Self::locate(n);
+        self.words[word] &= !mask;
+        self.len -= 1;
+        true
+    }
+    /** Returns true if n is in the set */
+    pub fn contains(&self, n: usize) -> bool {
+        let (word, mask) = Self::locate(n);
+        self.words.get(word).is_some_and(|w| w & mask != 0)
+    }
+    /** Returns the number of values in the set in O(1) time */
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /** Returns true if the set contains no values */
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /** Yields the values in the set in ascending order; Skips each set bit directly
+    with trailing_zeros() instead of testing all 64 bits of every word */
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut remaining = word;
+            std::iter::from_fn(move || {
+                if remaining == 0 {
+                    return None;
+                }
+                let bit = remaining.trailing_zeros() as usize;
+                remaining &= remaining - 1; // Clears the lowest set bit
+                Some(i * WORD_BITS + bit)
+            })
+        })
+    }
+    /** Returns a new set with the values in either set */
+    pub fn union(&self, other: &BitSet) -> BitSet {
+        let (longer, shorter) = if self.words.len() >= other.words.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut words = longer.words.clone();
+        for (w, o) in words.iter_mut().zip(&shorter.words) {
+            *w |= o;
+        }
+        Self::from_words(words)
+    }
+    /** Returns a new set with the values in both sets */
+    pub fn intersection(&self, other: &BitSet) -> BitSet {
+        let words = self.words.iter().zip(&other.words).map(|(w, o)| w & o).collect();
+        Self::from_words(words)
+    }
+    /** Returns a new set with the values in self that aren't in other */
+    pub fn difference(&self, other: &BitSet) -> BitSet {
+        let mut words = self.words.clone();
+        for (w, o) in words.iter_mut().zip(&other.words) {
+            *w &= !o;
+        }
+        Self::from_words(words)
+    }
+    // Returns the index of n's word and a mask with only n's bit set
+    fn locate(n: usize) -> (usize, u64) {
+        (n / WORD_BITS, 1 << (n % WORD_BITS))
+    }
+    // Builds a set from raw words, recounting the length one word at a time
+    fn from_words(words: Vec<u64>) -> BitSet {
+        let len = words.iter().map(|w| w.count_ones() as usize).sum();
+        BitSet { words, len }
+    }
+}
+/** Two sets are equal if they contain the same values, even if one
+has extra (empty) words left over from a larger value being removed */
+impl PartialEq for BitSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+#[test]
+fn bit_set_test() {
+    let mut set = BitSet::new();
+    assert!(set.is_empty());
+    assert!(!set.contains(0));
+    assert!(!set.remove(0));
+
+    // Sparse values spread across several words
+    for n in [1000, 3, 64, 0, 63, 129] {
+        assert!(set.insert(n));
+    }
+    assert!(!set.insert(64)); // Duplicates don't count twice
+    assert_eq!(set.len(), 6);
+    assert!(set.contains(63));
+    assert!(set.contains(64));
+    assert!(!set.contains(65));
+    assert!(!set.contains(100_000)); // Past the end of the word array
+
+    // Iteration is ascending regardless of insertion order
+    assert_eq!(set.iter().collect::<Vec<usize>>(), vec![0, 3, 63, 64, 129, 1000]);
+
+    // Removal
+    assert!(set.remove(3));
+    assert!(!set.remove(3));
+    assert!(!set.contains(3));
+    assert_eq!(set.len(), 5);
+
+    // Dense values fill whole words
+    let mut dense = BitSet::new();
+    for n in 0..256 {
+        dense.insert(n);
+    }
+    assert_eq!(dense.len(), 256);
+    assert_eq!(dense.iter().collect::<Vec<usize>>(), (0..256).collect::<Vec<usize>>());
+}
+
+#[test]
+fn bit_set_operations_test() {
+    let mut evens = BitSet::new();
+    let mut small = BitSet::new();
+    for n in (0..200).step_by(2) {
+        evens.insert(n);
+    }
+    for n in 0..10 {
+        small.insert(n);
+    }
+
+    let union = evens.union(&small);
+    assert_eq!(union.len(), 105); // 100 evens plus the 5 small odds
+    assert!(union.contains(7) && union.contains(198));
+    // Order of operands doesn't matter
+    assert!(union == small.union(&evens));
+
+    let intersection = evens.intersection(&small);
+    assert_eq!(intersection.iter().collect::<Vec<usize>>(), vec![0, 2, 4, 6, 8]);
+    assert!(intersection == small.intersection(&evens));
+
+    let difference = small.difference(&evens);
+    assert_eq!(difference.iter().collect::<Vec<usize>>(), vec![1, 3, 5, 7, 9]);
+    assert_eq!(evens.difference(&small).len(), 95);
+
+    // Sets compare by contents, not by word array length
+    let mut a = BitSet::new();
+    let mut b = BitSet::new();
+    a.insert(5);
+    b.insert(5);
+    b.insert(500);
+    assert!(a != b);
+    b.remove(500);
+    assert!(a == b);
+    assert!(a.difference(&b).is_empty());
+}
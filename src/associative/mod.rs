@@ -0,0 +1,2 @@
+pub mod bit_set;
+pub mod trie;
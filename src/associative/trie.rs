@@ -0,0 +1,168 @@
+/////////////////////////////////////////
+/** An arena-backed trie for &str keys */
+/////////////////////////////////////////
+
+use std::collections::HashMap;
+
+// Each node maps characters to the arena index of the child they lead to;
+// A node only carries a value if some key ends there
+struct TrieNode<V> {
+    children: HashMap<char, usize>,
+    value: Option<V>,
+}
+impl<V> TrieNode<V> {
+    fn new() -> TrieNode<V> {
+        TrieNode {
+            children: HashMap::new(),
+            value: None,
+        }
+    }
+}
+
+/** A prefix tree that stores its nodes in a Vec and links them by index,
+which sidesteps the ownership gymnastics of Box- or pointer-linked nodes;
+The root (index 0) represents the empty string.
+
+Child transitions are stored in std's HashMap
+
+The Trie's public API contains the following functions:
+ - new() -> Trie<V>
+ - insert(&mut self, key: &str, value: V) -> Option<V>
+ - get(&self, key: &str) -> Option<&V>
+ - contains_key(&self, key: &str) -> bool
+ - keys_with_prefix(&self, prefix: &str) -> Vec<String>
+ - len(&self) -> usize
+ - is_empty(&self) -> bool
+NOTE: insert() and get() run in O(k) time where k is the number of characters in the key */
+pub struct Trie<V> {
+    arena: Vec<TrieNode<V>>,
+    len: usize,
+}
+impl<V> Trie<V> {
+    /** Creates an empty trie containing only the root node */
+    pub fn new() -> Trie<V> {
+        Trie {
+            arena: vec![TrieNode::new()],
+            len: 0,
+        }
+    }
+    /** Inserts a value under the key, creating nodes along the way as needed;
+    Returns the old value if the key was already present */
+    pub fn insert(&mut self, key: &str, value: V) -> Option<V> {
+        let mut index = 0;
+        for c in key.chars() {
+            index = match self.arena[index].children.get(&c) {
+                Some(&child) => child,
+                None => {
+                    // Pushes the new node and links it to its parent by index
+                    let child = self.arena.len();
+                    self.arena.push(TrieNode::new());
+                    self.arena[index].children.insert(c, child);
+                    child
+                }
+            };
+        }
+        let old = self.arena[index].value.replace(value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+    /** Returns a reference to the value stored under the key, if there is one */
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.find(key).and_then(|index| self.arena[index].value.as_ref())
+    }
+    /** Returns true if a value is stored under the key */
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+    /** Returns every stored key that starts with the prefix, in lexicographic order;
+    Runs in O(k + m log m) time where m is the number of nodes below the prefix */
+    pub fn keys_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let mut keys = Vec::new();
+        let Some(start) = self.find(prefix) else {
+            return keys;
+        };
+        // Depth-first walk that carries each node's full key along with its index
+        let mut stack = vec![(start, prefix.to_string())];
+        while let Some((index, key)) = stack.pop() {
+            let node = &self.arena[index];
+            if node.value.is_some() {
+                keys.push(key.clone());
+            }
+            for (c, &child) in &node.children {
+                let mut child_key = key.clone();
+                child_key.push(*c);
+                stack.push((child, child_key));
+            }
+        }
+        // HashMap iteration order is arbitrary, so the results are sorted
+        keys.sort();
+        keys
+    }
+    /** Returns the number of keys in the trie */
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /** Returns true if the trie contains no keys */
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    // Follows the key's characters from the root, returning the index of the last node
+    fn find(&self, key: &str) -> Option<usize> {
+        let mut index = 0;
+        for c in key.chars() {
+            index = *self.arena[index].children.get(&c)?;
+        }
+        Some(index)
+    }
+}
+impl<V> Default for Trie<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[test]
+fn trie_test() {
+    let mut trie: Trie<u32> = Trie::new();
+    assert!(trie.is_empty());
+    assert_eq!(trie.get(""), None);
+
+    // Basic insert/get, including keys that are prefixes of other keys
+    assert_eq!(trie.insert("car", 1), None);
+    assert_eq!(trie.insert("cart", 2), None);
+    assert_eq!(trie.insert("care", 3), None);
+    assert_eq!(trie.insert("cat", 4), None);
+    assert_eq!(trie.insert("dog", 5), None);
+    assert_eq!(trie.insert("çà", 6), None); // Multi-byte chars are a single step
+    assert_eq!(trie.len(), 6);
+    assert_eq!(trie.get("car"), Some(&1));
+    assert_eq!(trie.get("cart"), Some(&2));
+    assert_eq!(trie.get("çà"), Some(&6));
+
+    // Missing keys, including a prefix that exists only as a path
+    assert_eq!(trie.get("ca"), None);
+    assert_eq!(trie.get("cars"), None);
+    assert_eq!(trie.get("bird"), None);
+    assert!(!trie.contains_key("ca"));
+
+    // Overwrites return the old value and don't change the length
+    assert_eq!(trie.insert("car", 10), Some(1));
+    assert_eq!(trie.get("car"), Some(&10));
+    assert_eq!(trie.len(), 6);
+
+    // Prefix queries return every completion, including the prefix itself
+    assert_eq!(trie.keys_with_prefix("car"), vec!["car", "care", "cart"]);
+    assert_eq!(trie.keys_with_prefix("ca"), vec!["car", "care", "cart", "cat"]);
+    assert_eq!(trie.keys_with_prefix("do"), vec!["dog"]);
+    assert!(trie.keys_with_prefix("x").is_empty());
+    assert!(trie.keys_with_prefix("cartography").is_empty());
+    // The empty prefix matches every key
+    assert_eq!(trie.keys_with_prefix("").len(), trie.len());
+
+    // The empty string is a valid key stored at the root
+    trie.insert("", 0);
+    assert_eq!(trie.get(""), Some(&0));
+    assert_eq!(trie.keys_with_prefix("")[0], "");
+}
@@ -0,0 +1,2 @@
+pub mod running_median;
+pub mod union_find;
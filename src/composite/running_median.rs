@@ -0,0 +1,105 @@
+/////////////////////////////////////////////////
+/** A running median over two priority queues */
+/////////////////////////////////////////////////
+
+use crate::lists::queues::priority_queue::sorted_list::{PriorityQueue, SortedVecQueue};
+use std::cmp::Reverse;
+
+/** Tracks the median of a stream of values by splitting them into a lower and upper half;
+The lower half is a MAX queue (keyed by `Reverse<T>`) and the upper half is a MIN queue,
+so the median always sits at the front of the lower half. The halves are rebalanced on each
+add so that the lower half holds either the same number of values as the upper half or one more.
+For even counts there's no generic way to average two `T`s, so the lower of the two middle
+values is reported.
+
+Both halves are SortedVecQueues, so add() runs in O(n) time; median() is O(1)
+
+The MedianTracker's public API contains the following functions:
+ - new() -> MedianTracker<T>
+ - add(&mut self, value: T)
+ - median(&self) -> Option<T>
+ - len(&self) -> usize
+ - is_empty(&self) -> bool
+*/
+pub struct MedianTracker<T: Ord + Clone> {
+    lower: SortedVecQueue<Reverse<T>, T>,
+    upper: SortedVecQueue<T, T>,
+}
+impl<T: Ord + Clone> MedianTracker<T> {
+    /** Creates a new, empty tracker */
+    pub fn new() -> MedianTracker<T> {
+        MedianTracker {
+            lower: SortedVecQueue::new(),
+            upper: SortedVecQueue::new(),
+        }
+    }
+    /** Adds a value to the stream and rebalances the halves */
+    pub fn add(&mut self, value: T) {
+        // Values at or below the current median go in the lower half
+        match self.lower.peek() {
+            Some(max) if value > *max => {
+                self.upper.enqueue(value.clone(), value).ok();
+            }
+            _ => {
+                self.lower.enqueue(Reverse(value.clone()), value).ok();
+            }
+        }
+        // Moves a single value across to restore the size invariant
+        if self.lower.size() > self.upper.size() + 1 {
+            if let Some(v) = self.lower.dequeue() {
+                self.upper.enqueue(v.clone(), v).ok();
+            }
+        } else if self.upper.size() > self.lower.size() {
+            if let Some(v) = self.upper.dequeue() {
+                self.lower.enqueue(Reverse(v.clone()), v).ok();
+            }
+        }
+    }
+    /** Returns the median (the lower median for even counts) in O(1) time,
+    or None if no values have been added */
+    pub fn median(&self) -> Option<T> {
+        self.lower.peek().cloned()
+    }
+    /** Returns the number of values added so far */
+    pub fn len(&self) -> usize {
+        self.lower.size() + self.upper.size()
+    }
+    /** Returns true if no values have been added */
+    pub fn is_empty(&self) -> bool {
+        self.lower.is_empty()
+    }
+}
+impl<T: Ord + Clone> Default for MedianTracker<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[test]
+fn running_median_test() {
+    let mut tracker: MedianTracker<i32> = MedianTracker::new();
+    assert_eq!(tracker.median(), None);
+    assert!(tracker.is_empty());
+
+    // Checks the median after every insertion, including duplicates
+    // and values that land on either side of the current median
+    let stream = [5, 15, 1, 3, 8, 7, 9, 10, 20, 2, 3];
+    let expected = [5, 5, 5, 3, 5, 5, 7, 7, 8, 7, 7];
+    for (i, (&v, &m)) in stream.iter().zip(expected.iter()).enumerate() {
+        tracker.add(v);
+        assert_eq!(tracker.len(), i + 1);
+        assert_eq!(tracker.median(), Some(m));
+        // Checks against a brute-force lower median
+        let mut sorted = stream[..=i].to_vec();
+        sorted.sort();
+        assert_eq!(tracker.median(), Some(sorted[i / 2]));
+    }
+
+    // Works with any Ord + Clone type
+    let mut words: MedianTracker<String> = MedianTracker::new();
+    for w in ["pear", "apple", "fig", "kiwi"] {
+        words.add(w.to_string());
+    }
+    // apple, fig, kiwi, pear
+    assert_eq!(words.median(), Some("fig".to_string()));
+}
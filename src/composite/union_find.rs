@@ -0,0 +1,126 @@
+////////////////////////////////////////////
+/** A union-find (disjoint set) structure */
+////////////////////////////////////////////
+
+/** Tracks a fixed number of elements, 0..n, partitioned into disjoint sets;
+Each set is a tree stored in a parent array where each root is its own parent.
+find() compresses paths by pointing every visited element straight at its root,
+and union() hangs the shorter tree under the taller one (by rank) so the trees
+stay shallow. Together they make each operation effectively O(1) amortized.
+
+The UnionFind's public API contains the following functions:
+ - new(n: usize) -> UnionFind
+ - find(&mut self, element: usize) -> usize
+ - union(&mut self, a: usize, b: usize) -> bool
+ - connected(&mut self, a: usize, b: usize) -> bool
+ - count(&self) -> usize
+ - len(&self) -> usize
+NOTE: find(), union(), and connected() panic if an element is out of bounds */
+pub struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<usize>,
+    count: usize,
+}
+impl UnionFind {
+    /** Creates n singleton sets in O(n) time */
+    pub fn new(n: usize) -> UnionFind {
+        UnionFind {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            count: n,
+        }
+    }
+    /** Returns the root that represents the element's set */
+    pub fn find(&mut self, element: usize) -> usize {
+        // First pass finds the root
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // Second pass points everything on the path directly at the root
+        let mut current = element;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+        root
+    }
+    /** Merges the sets containing a and b; Returns false if they were already the same set */
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (root_a, root_b) = (self.find(a), self.find(b));
+        if root_a == root_b {
+            return false;
+        }
+        // Only merging two trees of equal rank makes the result taller
+        match self.rank[root_a].cmp(&self.rank[root_b]) {
+            std::cmp::Ordering::Less => self.parent[root_a] = root_b,
+            std::cmp::Ordering::Greater => self.parent[root_b] = root_a,
+            std::cmp::Ordering::Equal => {
+                self.parent[root_b] = root_a;
+                self.rank[root_a] += 1;
+            }
+        }
+        self.count -= 1;
+        true
+    }
+    /** Returns true if a and b are in the same set */
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+    /** Returns the number of disjoint sets */
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    /** Returns the number of elements across all sets */
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+}
+
+#[test]
+fn union_find_test() {
+    let mut uf = UnionFind::new(8);
+    assert_eq!(uf.count(), 8);
+    assert_eq!(uf.len(), 8);
+    assert!(uf.connected(3, 3));
+    assert!(!uf.connected(0, 1));
+
+    // Builds {0, 1, 2} and {3, 4}, leaving 5, 6, and 7 alone
+    assert!(uf.union(0, 1));
+    assert!(uf.union(2, 1));
+    assert_eq!(uf.count(), 6);
+    assert!(uf.union(3, 4));
+    assert_eq!(uf.count(), 5);
+    assert!(uf.connected(0, 2));
+    assert!(uf.connected(4, 3));
+    assert!(!uf.connected(2, 3));
+    assert!(!uf.connected(5, 6));
+
+    // Unioning elements that are already connected changes nothing
+    assert!(!uf.union(0, 2));
+    assert_eq!(uf.count(), 5);
+
+    // Merging two components joins every member of both
+    assert!(uf.union(4, 1));
+    assert_eq!(uf.count(), 4);
+    for a in 0..5 {
+        for b in 0..5 {
+            assert!(uf.connected(a, b));
+        }
+    }
+    assert!(!uf.connected(0, 7));
+
+    // Every element ends up pointing directly at the root after a find
+    let root = uf.find(2);
+    for e in 0..5 {
+        uf.find(e);
+        assert_eq!(uf.parent[e], root);
+    }
+
+    // Merges down to a single set
+    uf.union(5, 6);
+    uf.union(7, 6);
+    uf.union(7, 0);
+    assert_eq!(uf.count(), 1);
+}
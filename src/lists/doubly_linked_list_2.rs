@@ -27,7 +27,13 @@ impl<'a> Node<'a> {
  - new() -> List
  - insert(&mut self, node: Box<Node>)
  - remove(&mut self, name: String)
+ - remove_at(&mut self, index: usize) -> Option<Box<Node>>
+ - pop_head(&mut self) -> Option<Box<Node>>
+ - partition<F: Fn(&Node) -> bool>(self, f: F) -> (List, List)
  - iter(&self) -> Iter
+ - pairs(&self) -> impl Iterator<Item = (&Node, &Node)>
+ - is_sorted(&self) -> bool
+ - into_iter(self) -> IntoIter
  - print(&self)
  - print_rev(&self)
 */
@@ -184,12 +190,109 @@ impl<'a> List<'a> {
             //println!("Node not found: {}", name);
         }
     }
+    /** Removes and returns the node at a provided index in O(n) time, walking
+    from whichever end is closer; Returns None if the index is out of bounds.
+    Removing a node can't break the sorted invariant */
+    pub fn remove_at(&mut self, index: usize) -> Option<Box<Node<'a>>> {
+        if index >= self.length {
+            return None;
+        }
+        unsafe {
+            // Finds the removal node from the closer end
+            let current = if index < self.length / 2 {
+                let mut current = self.head?;
+                for _ in 0..index {
+                    current = (*current).next?;
+                }
+                current
+            } else {
+                let mut current = self.tail?;
+                for _ in index..self.length - 1 {
+                    current = (*current).prev?;
+                }
+                current
+            };
+            // Re-boxes the node so the caller owns it
+            let mut removed = Box::from_raw(current);
+            // a.next -> c, or c becomes the head
+            match removed.prev {
+                Some(prev) => (*prev).next = removed.next,
+                None => self.head = removed.next,
+            }
+            // c.prev -> a, or a becomes the tail
+            match removed.next {
+                Some(next) => (*next).prev = removed.prev,
+                None => self.tail = removed.prev,
+            }
+            removed.prev = None;
+            removed.next = None;
+            self.length -= 1;
+            Some(removed)
+        }
+    }
+    /** Removes and returns the head node in O(1) time */
+    pub fn pop_head(&mut self) -> Option<Box<Node<'a>>> {
+        self.head.map(|head_ptr| unsafe {
+            // Re-boxes the head so the caller owns it
+            let mut old_head = Box::from_raw(head_ptr);
+            self.head = old_head.next;
+            match self.head {
+                Some(next) => (*next).prev = None,
+                // Removed the only Node
+                None => self.tail = None,
+            }
+            old_head.next = None;
+            self.length -= 1;
+            old_head
+        })
+    }
+    /** Consumes the list and relinks each node into a "true" or "false" list
+    by the predicate in O(n) time; Nodes keep their relative order, so both
+    lists stay sorted without re-inserting anything */
+    pub fn partition<F: Fn(&Node<'a>) -> bool>(mut self, f: F) -> (List<'a>, List<'a>) {
+        let mut matches = List::new();
+        let mut rest = List::new();
+        while let Some(node) = self.pop_head() {
+            if f(&node) {
+                matches.push_tail(node);
+            } else {
+                rest.push_tail(node);
+            }
+        }
+        (matches, rest)
+    }
+    /** Appends a node to the tail in O(1) time without checking the sort order;
+    Only used to move already-sorted nodes from another list */
+    fn push_tail(&mut self, node: Box<Node<'a>>) {
+        let new_node_ptr: *mut Node = Box::into_raw(node);
+        unsafe {
+            (*new_node_ptr).prev = self.tail;
+            (*new_node_ptr).next = None;
+            match self.tail {
+                Some(tail) => (*tail).next = Some(new_node_ptr),
+                None => self.head = Some(new_node_ptr),
+            }
+        }
+        self.tail = Some(new_node_ptr);
+        self.length += 1;
+    }
     pub fn iter(&self) -> Iter<'a> {
         Iter {
             next: self.head.as_ref().map(|&ptr| unsafe { &*ptr }),
             prev: self.tail.as_ref().map(|&ptr| unsafe { &*ptr }),
         }
     }
+    /** Returns each pair of adjacent Nodes from head to tail, such that a list
+    of n Nodes yields n - 1 pairs */
+    pub fn pairs(&self) -> impl Iterator<Item = (&'a Node<'a>, &'a Node<'a>)> {
+        self.iter().zip(self.iter().skip(1))
+    }
+    /** Returns true if scores descend from head to tail, with unscored Nodes
+    last, which is the order insert() maintains; Ties count as sorted */
+    pub fn is_sorted(&self) -> bool {
+        // None < Some(_), so a plain comparison puts unscored Nodes last
+        self.pairs().all(|(a, b)| a.score >= b.score)
+    }
     pub fn print_fwd(&self, rev: bool) {
         let none = "";
         if rev {
@@ -257,6 +360,25 @@ impl<'a> DoubleEndedIterator for Iter<'a> {
         })
     }
 }
+/** An owning iterator that pops each Node from the head, so the list
+is consumed in head-to-tail (sorted) order; Any Nodes left unconsumed
+are freed by the list's destructor when the iterator drops */
+pub struct IntoIter<'a> {
+    list: List<'a>,
+}
+impl<'a> Iterator for IntoIter<'a> {
+    type Item = Box<Node<'a>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_head()
+    }
+}
+impl<'a> IntoIterator for List<'a> {
+    type Item = Box<Node<'a>>;
+    type IntoIter = IntoIter<'a>;
+    fn into_iter(self) -> IntoIter<'a> {
+        IntoIter { list: self }
+    }
+}
 impl<'a> Drop for List<'a> {
     /** List destructor */
     fn drop(&mut self) {
@@ -276,6 +398,18 @@ impl<'a> Drop for List<'a> {
     }
 }
 
+/** Moves each node out of a singly-linked list in head-to-tail order,
+appending it to the new list's tail to preserve the original order in O(n) time */
+impl<'a> From<crate::lists::singly_linked_list::List<'a>> for List<'a> {
+    fn from(mut other: crate::lists::singly_linked_list::List<'a>) -> List<'a> {
+        let mut list = List::new();
+        while let Some(node) = other.pop_head() {
+            list.push_tail(Node::new(node.name, node.score));
+        }
+        list
+    }
+}
+
 #[test]
 fn test() {
     // Creates a new doubly-linked list
@@ -383,6 +517,226 @@ fn test() {
     }
 }
 
+#[test]
+fn conversion_test() {
+    use crate::lists::singly_linked_list;
+
+    let mut singly = singly_linked_list::List::new();
+    singly.insert(singly_linked_list::Node::new("Peter", Some(1223)));
+    singly.insert(singly_linked_list::Node::new("Dangus", None));
+    singly.insert(singly_linked_list::Node::new("Remus", Some(8234)));
+    singly.insert(singly_linked_list::Node::new("Brain", Some(616)));
+
+    // Singly -> doubly preserves order and length in both directions
+    let doubly = List::from(singly);
+    assert_eq!(doubly.length, 4);
+    let names: Vec<&str> = doubly.iter().map(|n| n.name).collect();
+    assert_eq!(names, vec!["Remus", "Peter", "Brain", "Dangus"]);
+    let names: Vec<&str> = doubly.iter().rev().map(|n| n.name).collect();
+    assert_eq!(names, vec!["Dangus", "Brain", "Peter", "Remus"]);
+
+    // Doubly -> singly round-trips back to the original order
+    let mut singly = singly_linked_list::List::from(doubly);
+    let mut round_trip = Vec::new();
+    while let Some(node) = singly.pop_head() {
+        round_trip.push((node.name, node.score));
+    }
+    assert_eq!(
+        round_trip,
+        vec![
+            ("Remus", Some(8234)),
+            ("Peter", Some(1223)),
+            ("Brain", Some(616)),
+            ("Dangus", None)
+        ]
+    );
+
+    // Empty lists convert to empty lists
+    let empty: List = List::from(singly_linked_list::List::new());
+    assert_eq!(empty.length, 0);
+    assert!(empty.head.is_none() && empty.tail.is_none());
+}
+
+#[test]
+fn into_iter_test() {
+    let mut list = List::new();
+    list.insert(Node::new("Peter", Some(1223)));
+    list.insert(Node::new("Dangus", None));
+    list.insert(Node::new("Remus", Some(8234)));
+    list.insert(Node::new("Brain", Some(616)));
+
+    // Consumes the list head-to-tail in a for loop
+    let mut entries = Vec::new();
+    for node in list {
+        entries.push((node.name, node.score));
+    }
+    assert_eq!(
+        entries,
+        vec![
+            ("Remus", Some(8234)),
+            ("Peter", Some(1223)),
+            ("Brain", Some(616)),
+            ("Dangus", None)
+        ]
+    );
+
+    // A partially consumed iterator hands the rest to the list's destructor
+    let mut list = List::new();
+    list.insert(Node::new("Peter", Some(1223)));
+    list.insert(Node::new("Remus", Some(8234)));
+    list.insert(Node::new("Brain", Some(616)));
+    let mut iter = list.into_iter();
+    assert_eq!(iter.next().unwrap().name, "Remus");
+    assert_eq!(iter.list.length, 2);
+    drop(iter);
+
+    // Empty lists yield nothing
+    assert!(List::new().into_iter().next().is_none());
+}
+
+#[test]
+fn remove_at_test() {
+    // Checks that the list reads the same forward and backward, which
+    // only holds if every prev/next pair and the head/tail were relinked
+    fn names<'a>(list: &List<'a>) -> Vec<&'a str> {
+        let fwd: Vec<&str> = list.iter().map(|n| n.name).collect();
+        let mut rev: Vec<&str> = list.iter().rev().map(|n| n.name).collect();
+        rev.reverse();
+        assert_eq!(fwd, rev);
+        assert_eq!(fwd.len(), list.length);
+        fwd
+    }
+    let mut list = List::new();
+    list.insert(Node::new("Peter", Some(1223)));
+    list.insert(Node::new("Dingus", Some(12)));
+    list.insert(Node::new("Dangus", Some(23)));
+    list.insert(Node::new("Remus", Some(8234)));
+    list.insert(Node::new("Brain", Some(616)));
+    list.insert(Node::new("Bobson", Some(42069)));
+    // Bobson -> Remus -> Peter -> Brain -> Dangus -> Dingus
+
+    // Head
+    let removed = list.remove_at(0).unwrap();
+    assert_eq!(removed.name, "Bobson");
+    assert!(removed.prev.is_none() && removed.next.is_none());
+    assert_eq!(names(&list), vec!["Remus", "Peter", "Brain", "Dangus", "Dingus"]);
+
+    // Middle, walking from the head and from the tail
+    assert_eq!(list.remove_at(1).unwrap().name, "Peter");
+    assert_eq!(list.remove_at(2).unwrap().name, "Dangus");
+    assert_eq!(names(&list), vec!["Remus", "Brain", "Dingus"]);
+
+    // Tail
+    assert_eq!(list.remove_at(list.length - 1).unwrap().name, "Dingus");
+    assert_eq!(names(&list), vec!["Remus", "Brain"]);
+    assert_eq!(unsafe { (*list.tail.unwrap()).name }, "Brain");
+
+    // Out of bounds
+    assert!(list.remove_at(2).is_none());
+    assert!(list.remove_at(23).is_none());
+    assert_eq!(list.length, 2);
+
+    // Down to empty
+    list.remove_at(1);
+    list.remove_at(0);
+    assert!(list.head.is_none() && list.tail.is_none());
+    assert!(list.remove_at(0).is_none());
+}
+
+#[test]
+fn partition_test() {
+    fn podium<'a>() -> List<'a> {
+        let mut list = List::new();
+        list.insert(Node::new("Peter", Some(1223)));
+        list.insert(Node::new("Dingus", Some(12)));
+        list.insert(Node::new("Dangus", None));
+        list.insert(Node::new("Remus", Some(8234)));
+        list.insert(Node::new("Brain", Some(616)));
+        // Remus -> Peter -> Brain -> Dingus -> Dangus
+        list
+    }
+    fn names<'a>(list: &List<'a>) -> Vec<&'a str> {
+        list.iter().map(|n| n.name).collect()
+    }
+
+    // Splits by parity of score, keeping each side in sorted order
+    let (even, rest) = podium().partition(|n| n.score.is_some_and(|s| s % 2 == 0));
+    assert_eq!(names(&even), vec!["Remus", "Brain", "Dingus"]);
+    assert_eq!(names(&rest), vec!["Peter", "Dangus"]);
+    assert_eq!((even.length, rest.length), (3, 2));
+    // The relinked lists are intact in both directions
+    let rev: Vec<&str> = even.iter().rev().map(|n| n.name).collect();
+    assert_eq!(rev, vec!["Dingus", "Brain", "Remus"]);
+
+    // All true and all false
+    let (all, none) = podium().partition(|_| true);
+    assert_eq!(names(&all), vec!["Remus", "Peter", "Brain", "Dingus", "Dangus"]);
+    assert!(none.head.is_none() && none.tail.is_none());
+    let (none, all) = podium().partition(|_| false);
+    assert_eq!(none.length, 0);
+    assert_eq!(all.length, 5);
+
+    // Empty lists split into two empty lists
+    let (a, b) = List::new().partition(|_| true);
+    assert_eq!((a.length, b.length), (0, 0));
+}
+
+#[test]
+fn pairs_test() {
+    fn pair_names<'a>(list: &List<'a>) -> Vec<(&'a str, &'a str)> {
+        list.pairs().map(|(a, b)| (a.name, b.name)).collect()
+    }
+
+    // Empty and single-node lists have no pairs
+    let mut list = List::new();
+    assert!(pair_names(&list).is_empty());
+    list.insert(Node::new("Peter", Some(1223)));
+    assert!(pair_names(&list).is_empty());
+
+    // Two nodes make exactly one pair
+    list.insert(Node::new("Remus", Some(8234)));
+    assert_eq!(pair_names(&list), vec![("Remus", "Peter")]);
+
+    // Several nodes overlap by one in head-to-tail order
+    list.insert(Node::new("Brain", Some(616)));
+    list.insert(Node::new("Dangus", None));
+    assert_eq!(
+        pair_names(&list),
+        vec![("Remus", "Peter"), ("Peter", "Brain"), ("Brain", "Dangus")]
+    );
+}
+
+#[test]
+fn is_sorted_test() {
+    // Empty lists and lists built by insert() are always sorted
+    let mut list = List::new();
+    assert!(list.is_sorted());
+    list.insert(Node::new("Brain", Some(616)));
+    assert!(list.is_sorted());
+    list.insert(Node::new("Dangus", None));
+    list.insert(Node::new("Remus", Some(8234)));
+    list.insert(Node::new("Peter", Some(1223)));
+    assert!(list.is_sorted());
+
+    // Equal adjacent scores are still sorted
+    list.insert(Node::new("Dingus", Some(616)));
+    assert!(list.is_sorted());
+
+    // push_tail() skips the sort, so it can build a reverse-sorted list
+    let mut rev = List::new();
+    rev.push_tail(Node::new("Dangus", None));
+    rev.push_tail(Node::new("Brain", Some(616)));
+    rev.push_tail(Node::new("Peter", Some(1223)));
+    assert!(!rev.is_sorted());
+
+    // A single out-of-place node is enough to fail
+    let mut list = List::new();
+    list.push_tail(Node::new("Remus", Some(8234)));
+    list.push_tail(Node::new("Brain", Some(616)));
+    list.push_tail(Node::new("Peter", Some(1223)));
+    assert!(!list.is_sorted());
+}
+
 /** Runs example operations to demonstrate functionality */
 pub fn example() {
     use crate::lists::doubly_linked_list_2::{List, Node};
@@ -3,7 +3,7 @@
 /////////////////////////////////////////
 
 // A sorted, array-based priority queue
-mod sorted_list {
+pub mod sorted_list {
 pub use crate::lists::queues::traits::PriorityQueue; // Re-exports the trait
 
 pub struct Entry<K, V> {
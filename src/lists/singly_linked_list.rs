@@ -3,8 +3,8 @@
 /////////////////////////////////
 
 pub struct Node<'a> {
-    name: &'a str,
-    score: Option<i32>,
+    pub name: &'a str,
+    pub score: Option<i32>,
     next: Option<Box<Node<'a>>>,
 }
 impl<'a> Node<'a> {
@@ -21,6 +21,11 @@ impl<'a> Node<'a> {
  - new() -> List<'a>
  - insert(&mut self, node: Node<'a>)
  - remove(&mut self, index: u32)
+ - remove_at(&mut self, index: usize) -> Option<Node<'a>>
+ - remove_value(&mut self, name: &str) -> bool
+ - split_at_value(&mut self, name: &str) -> Option<List<'a>>
+ - pop_head(&mut self) -> Option<Node<'a>>
+ - nth_from_end(&self, n: usize) -> Option<&Node<'a>>
  - print_list(&mut self)
 */
 pub struct List<'a> {
@@ -106,6 +111,86 @@ impl<'a> List<'a> {
             iter_node_ref = &mut node.next;
         }
     }
+    /** Removes and returns the node at a provided index in O(n) time;
+    Returns None if the index is out of bounds */
+    pub fn remove_at(&mut self, index: usize) -> Option<Node<'a>> {
+        if index >= self.length {
+            return None;
+        }
+        // Walks the links up to the one that owns the removal node
+        let mut link = &mut self.head;
+        for _ in 0..index {
+            link = &mut link.as_mut()?.next;
+        }
+        // Splices the removal node out by handing its next to the predecessor's link
+        let mut removed = link.take()?;
+        *link = removed.next.take();
+        self.length -= 1;
+        Some(*removed)
+    }
+    /** Removes the first node with a matching name in O(n) time;
+    Returns true if a node was removed */
+    pub fn remove_value(&mut self, name: &str) -> bool {
+        // Walks the links until one owns a matching node or the list runs out
+        let mut link = &mut self.head;
+        while link.as_ref().is_some_and(|node| node.name != name) {
+            link = &mut link.as_mut().unwrap().next;
+        }
+        if let Some(mut removed) = link.take() {
+            *link = removed.next.take();
+            self.length -= 1;
+            true
+        } else {
+            false
+        }
+    }
+    /** Detaches the first node with a matching name and everything after it into
+    a new list in O(n) time, leaving self with only the nodes before it;
+    Returns None (and leaves self untouched) if no node matches. Both halves
+    are slices of the sorted original, so both keep the sorted invariant */
+    pub fn split_at_value(&mut self, name: &str) -> Option<List<'a>> {
+        // Walks the links, counting the nodes that stay behind
+        let mut link = &mut self.head;
+        let mut index = 0;
+        while link.as_ref().is_some_and(|node| node.name != name) {
+            link = &mut link.as_mut().unwrap().next;
+            index += 1;
+        }
+        // Cuts the list at the matching link, or bails if the list ran out
+        let head = link.take()?;
+        let split = List {
+            head: Some(head),
+            length: self.length - index,
+        };
+        self.length = index;
+        Some(split)
+    }
+    /** Removes and returns the head node in O(1) time */
+    pub fn pop_head(&mut self) -> Option<Node<'a>> {
+        self.head.take().map(|mut old_head| {
+            self.head = old_head.next.take();
+            self.length -= 1;
+            *old_head
+        })
+    }
+    /** Returns the node n positions before the tail (where 0 is the tail) in O(n) time;
+    Uses a lead pointer that runs n nodes ahead of a trailing pointer so that
+    the trailing pointer lands on the target when the lead hits the tail */
+    pub fn nth_from_end(&self, n: usize) -> Option<&Node<'a>> {
+        // Advances the lead pointer n nodes, bailing if the list runs out first
+        let mut lead = self.head.as_deref();
+        for _ in 0..n {
+            lead = lead?.next.as_deref();
+        }
+        let mut lead = lead?;
+        // Moves both pointers until the lead pointer is at the tail
+        let mut trail = self.head.as_deref()?;
+        while let Some(next) = lead.next.as_deref() {
+            lead = next;
+            trail = trail.next.as_deref()?;
+        }
+        Some(trail)
+    }
     /** Prints the whole list and nothing but the list */
     pub fn print_list(&mut self) {
         println!("Singly inked list contains {} elements:", self.length);
@@ -125,6 +210,22 @@ impl<'a> List<'a> {
     }
 }
 
+/** Moves each node out of a doubly-linked list in head-to-tail order,
+appending it to the new list's tail to preserve the original order in O(n) time */
+impl<'a> From<crate::lists::doubly_linked_list_2::List<'a>> for List<'a> {
+    fn from(mut other: crate::lists::doubly_linked_list_2::List<'a>) -> List<'a> {
+        let mut list = List::new();
+        // Tracks the tail's (empty) next link to avoid re-traversing for each append
+        let mut tail = &mut list.head;
+        while let Some(node) = other.pop_head() {
+            let new_tail = tail.insert(Box::new(Node::new(node.name, node.score)));
+            tail = &mut new_tail.next;
+            list.length += 1;
+        }
+        list
+    }
+}
+
 // Not a lot here to test aside from the list's length and the fact that opertions dont error
 #[test]
 fn basic_funciton_test() {
@@ -140,6 +241,115 @@ fn basic_funciton_test() {
     assert_eq!(list.length, 2);
 }
 
+// Collects the list's names in order for the tests below
+#[cfg(test)]
+fn names<'a>(list: &List<'a>) -> Vec<&'a str> {
+    let mut v = Vec::new();
+    let mut current = &list.head;
+    while let Some(node) = current {
+        v.push(node.name);
+        current = &node.next;
+    }
+    v
+}
+
+#[test]
+fn remove_at_value_test() {
+    let mut list = List::new();
+    list.insert(Node::new("Peter", Some(1223)));
+    list.insert(Node::new("Dingus", Some(12)));
+    list.insert(Node::new("Dangus", Some(23)));
+    list.insert(Node::new("Remus", Some(8234)));
+    list.insert(Node::new("Brain", Some(616)));
+    // Remus -> Peter -> Brain -> Dangus -> Dingus
+
+    // Head, middle, and tail by index
+    assert_eq!(list.remove_at(0).unwrap().name, "Remus");
+    assert_eq!(list.remove_at(1).unwrap().name, "Brain");
+    assert_eq!(list.remove_at(2).unwrap().name, "Dingus");
+    assert!(list.remove_at(2).is_none());
+    assert_eq!(names(&list), vec!["Peter", "Dangus"]);
+    assert_eq!(list.length, 2);
+
+    // Absent, tail, and head by value, ending with the only element
+    list.insert(Node::new("Bobson", Some(42069)));
+    assert!(!list.remove_value("Blorbson"));
+    assert!(list.remove_value("Dangus"));
+    assert!(list.remove_value("Bobson"));
+    assert_eq!(names(&list), vec!["Peter"]);
+    assert!(list.remove_value("Peter"));
+    assert!(list.head.is_none());
+    assert_eq!(list.length, 0);
+    assert!(!list.remove_value("Peter"));
+    assert!(list.remove_at(0).is_none());
+}
+
+#[test]
+fn nth_from_end_test() {
+    let mut list = List::new();
+    assert!(list.nth_from_end(0).is_none());
+
+    list.insert(Node::new("Peter", Some(1223)));
+    list.insert(Node::new("Dingus", Some(12)));
+    list.insert(Node::new("Dangus", Some(23)));
+    list.insert(Node::new("Remus", Some(8234)));
+    // Remus -> Peter -> Dangus -> Dingus
+
+    // The tail
+    assert_eq!(list.nth_from_end(0).unwrap().name, "Dingus");
+    assert_eq!(list.nth_from_end(1).unwrap().name, "Dangus");
+    // The head (n == len - 1)
+    assert_eq!(list.nth_from_end(list.length - 1).unwrap().name, "Remus");
+    // Out of range
+    assert!(list.nth_from_end(list.length).is_none());
+    assert!(list.nth_from_end(23).is_none());
+}
+
+#[test]
+fn split_at_value_test() {
+    fn podium<'a>() -> List<'a> {
+        let mut list = List::new();
+        list.insert(Node::new("Peter", Some(1223)));
+        list.insert(Node::new("Dingus", Some(12)));
+        list.insert(Node::new("Dangus", Some(23)));
+        list.insert(Node::new("Remus", Some(8234)));
+        // Remus -> Peter -> Dangus -> Dingus
+        list
+    }
+
+    // At the head, self becomes empty
+    let mut list = podium();
+    let split = list.split_at_value("Remus").unwrap();
+    assert!(names(&list).is_empty());
+    assert_eq!(list.length, 0);
+    assert_eq!(names(&split), vec!["Remus", "Peter", "Dangus", "Dingus"]);
+    assert_eq!(split.length, 4);
+
+    // In the middle
+    let mut list = podium();
+    let split = list.split_at_value("Dangus").unwrap();
+    assert_eq!(names(&list), vec!["Remus", "Peter"]);
+    assert_eq!(list.length, 2);
+    assert_eq!(names(&split), vec!["Dangus", "Dingus"]);
+    assert_eq!(split.length, 2);
+
+    // At the tail
+    let mut list = podium();
+    let split = list.split_at_value("Dingus").unwrap();
+    assert_eq!(names(&list), vec!["Remus", "Peter", "Dangus"]);
+    assert_eq!(list.length, 3);
+    assert_eq!(names(&split), vec!["Dingus"]);
+    assert_eq!(split.length, 1);
+    // The remaining list is still usable
+    assert_eq!(list.nth_from_end(0).unwrap().name, "Dangus");
+
+    // An absent name leaves the list alone
+    let mut list = podium();
+    assert!(list.split_at_value("Bobson").is_none());
+    assert_eq!(names(&list), vec!["Remus", "Peter", "Dangus", "Dingus"]);
+    assert_eq!(list.length, 4);
+}
+
 pub fn example() {
     // Creates a new (empty list)
     let mut podium: List = List::new();
@@ -0,0 +1,147 @@
+///////////////////////////////////////////
+/** A fixed-capacity, array-backed stack */
+///////////////////////////////////////////
+
+use std::mem::MaybeUninit;
+
+/** A stack that lives entirely in a `[MaybeUninit<T>; N]` with no heap allocation;
+Slots `0..len` are always initialized and slots `len..N` never are, so the stack
+only ever reads initialized memory and only ever drops what it wrote.
+
+The ArrayStack's public API contains the following functions:
+ - new() -> ArrayStack<T, N>
+ - push(&mut self, item: T) -> Result<(), T>
+ - pop(&mut self) -> Option<T>
+ - peek(&self) -> Option<&T>
+ - len(&self) -> usize
+ - is_empty(&self) -> bool
+ - capacity(&self) -> usize
+NOTE: All functions operate in O(1) time, except Drop which runs in O(n) */
+pub struct ArrayStack<T, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    len: usize,
+}
+impl<T, const N: usize> ArrayStack<T, N> {
+    /** Creates an empty stack; No slots are initialized */
+    pub fn new() -> ArrayStack<T, N> {
+        ArrayStack {
+            // An array of MaybeUninit doesn't need initializing, and the
+            // inline const lets this work for types that aren't Copy
+            data: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+    /** Pushes an item onto the stack, or hands it back as Err(item) if the stack is full */
+    pub fn push(&mut self, item: T) -> Result<(), T> {
+        if self.len == N {
+            return Err(item);
+        }
+        self.data[self.len].write(item);
+        self.len += 1;
+        Ok(())
+    }
+    /** Removes and returns the top item, if there is one */
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // SAFETY: Slot len was initialized by push, and decrementing len
+        // first means the slot is treated as uninitialized from here on,
+        // so the value is moved out exactly once
+        Some(unsafe { self.data[self.len].assume_init_read() })
+    }
+    /** Returns a reference to the top item, if there is one */
+    pub fn peek(&self) -> Option<&T> {
+        if self.len == 0 {
+            return None;
+        }
+        // SAFETY: Every slot below len is initialized
+        Some(unsafe { self.data[self.len - 1].assume_init_ref() })
+    }
+    /** Returns the number of items on the stack */
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /** Returns true if the stack has no items */
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /** Returns the fixed number of items the stack can hold */
+    pub fn capacity(&self) -> usize {
+        N
+    }
+}
+impl<T, const N: usize> Default for ArrayStack<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+/** MaybeUninit never drops its contents, so the stack drops the initialized slots itself */
+impl<T, const N: usize> Drop for ArrayStack<T, N> {
+    fn drop(&mut self) {
+        for slot in &mut self.data[..self.len] {
+            // SAFETY: Every slot below len is initialized and is dropped only once here
+            unsafe { slot.assume_init_drop() }
+        }
+    }
+}
+
+#[test]
+fn array_stack_test() {
+    let mut s: ArrayStack<String, 3> = ArrayStack::new();
+    assert!(s.is_empty());
+    assert_eq!(s.peek(), None);
+    assert_eq!(s.pop(), None);
+    assert_eq!(s.capacity(), 3);
+
+    // Fills the stack to capacity
+    for word in ["a", "b", "c"] {
+        assert!(s.push(word.to_string()).is_ok());
+    }
+    assert_eq!(s.len(), 3);
+    assert_eq!(s.peek(), Some(&"c".to_string()));
+
+    // Overflow hands the item back and leaves the stack alone
+    assert_eq!(s.push("d".to_string()), Err("d".to_string()));
+    assert_eq!(s.len(), 3);
+    assert_eq!(s.peek(), Some(&"c".to_string()));
+
+    // LIFO order, then space frees up again
+    assert_eq!(s.pop(), Some("c".to_string()));
+    assert!(s.push("e".to_string()).is_ok());
+    assert_eq!(s.pop(), Some("e".to_string()));
+    assert_eq!(s.pop(), Some("b".to_string()));
+    assert_eq!(s.pop(), Some("a".to_string()));
+    assert_eq!(s.pop(), None);
+    assert!(s.is_empty());
+
+    // A zero-capacity stack is always full
+    let mut z: ArrayStack<u8, 0> = ArrayStack::new();
+    assert_eq!(z.push(1), Err(1));
+}
+
+#[test]
+fn array_stack_drop_test() {
+    use std::rc::Rc;
+    // Each Rc clone on the stack bumps the strong count, so the count
+    // shows exactly how many items are still alive
+    let tracker = Rc::new(());
+    {
+        let mut s: ArrayStack<Rc<()>, 8> = ArrayStack::new();
+        for _ in 0..5 {
+            s.push(Rc::clone(&tracker)).unwrap();
+        }
+        assert_eq!(Rc::strong_count(&tracker), 6);
+        // Popped items are owned by the caller and dropped here
+        drop(s.pop());
+        assert_eq!(Rc::strong_count(&tracker), 5);
+        // The remaining four are dropped with the partially filled stack
+    }
+    assert_eq!(Rc::strong_count(&tracker), 1);
+
+    // An empty stack drops nothing
+    let s: ArrayStack<Rc<()>, 4> = ArrayStack::new();
+    drop(s);
+    assert_eq!(Rc::strong_count(&tracker), 1);
+}
@@ -1,3 +1,4 @@
+pub mod array_stack;
 pub mod safe_linked_stack;
 pub mod unsafe_linked_stack;
 pub mod vector_stack;
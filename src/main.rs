@@ -1,5 +1,8 @@
 #![allow(dead_code, unused_imports)]
 
+mod algorithms;
+mod associative;
+mod composite;
 mod lists;
 mod maw;
 mod tgg;
@@ -151,7 +154,7 @@ fn main() {
 
     // Queues
     //println!("\nQueues:");
-    //lists::queues::vec_circ_queue::empirical_test();
+    //println!("{:?}", lists::queues::vec_circ_queue::benchmark(10_000, 1_000_000));
 
     // Generic List ADT
     //println!("\nGeneric List ADT:");
@@ -208,4 +211,9 @@ fn binary_search_example() {
         }
         None => println!("The target {target} is not in the array"),
     };
+    // The keyed variant reports where a missing target would go
+    let missing = 58;
+    if let Err(index) = maw::maw_01::binary_search_by_key(&array, &missing, |e| *e) {
+        println!("The target {missing} is missing, but would be inserted at index {index}.");
+    }
 }
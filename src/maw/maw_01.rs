@@ -37,6 +37,9 @@ pub fn binary_search(a: &[i32], key: i32) -> Option<i32> {
     return None;
 }
 
+// The keyed variant lives with the other searching helpers in the algorithms module
+pub use crate::algorithms::binary_search_by_key;
+
 #[test]
 pub fn binary_search_test() {
     // The target 73 exists at the 37th index
@@ -138,6 +138,72 @@ pub fn disk_usage(root: &Path) -> u64 {
     }
     return dir_size;
 }
+// Replaces the call stack with an explicit Vec-based stack so deeply nested
+// directories can't overflow it. Each directory is pushed twice; once to
+// expand its children and once (after its children) to print its size, which
+// mirrors the postorder output of the recursive version. Sizes for in-progress
+// directories live on a second stack. Classifies entries with symlink_metadata
+// so symlinks are never followed, which avoids symlink loops.
+/** Iteratively walks a directory tree printing out names and sizes in O(n) time */
+pub fn disk_usage_iter(root: &Path) -> u64 {
+    let mut total = 0;
+    let mut stack: Vec<(std::path::PathBuf, bool)> = vec![(root.to_path_buf(), false)];
+    let mut dir_sizes: Vec<u64> = Vec::new();
+    while let Some((path, expanded)) = stack.pop() {
+        let meta = std::fs::symlink_metadata(&path).expect("metadata call failed [0]");
+        if meta.is_dir() {
+            if expanded {
+                // All children have been visited, so the directory's size is final
+                let dir_size = dir_sizes.pop().unwrap_or(0);
+                println!("d {:>7}B  {}", dir_size + meta.len(), path.display());
+                if let Some(parent_size) = dir_sizes.last_mut() {
+                    *parent_size += dir_size;
+                }
+            } else {
+                stack.push((path.clone(), true));
+                dir_sizes.push(0);
+                let entries: Vec<std::path::PathBuf> = path
+                    .read_dir()
+                    .expect("read_dir call failed")
+                    .map(|e| e.expect("failure to deconstruct value").path())
+                    .collect();
+                // Pushes in reverse so entries are visited in read_dir order
+                for entry in entries.into_iter().rev() {
+                    stack.push((entry, false));
+                }
+            }
+        } else if meta.is_file() {
+            let size = meta.len();
+            println!("  {:>7}B  {}", size, path.display());
+            if let Some(parent_size) = dir_sizes.last_mut() {
+                *parent_size += size;
+            }
+            total += size;
+        }
+    }
+    total
+}
+#[test]
+pub fn disk_usage_iter_test() {
+    // Builds a small fixture in the system temp directory
+    let root = std::env::temp_dir().join(format!("disk_usage_iter_{}", std::process::id()));
+    let nested = root.join("a").join("b").join("c");
+    std::fs::create_dir_all(&nested).unwrap();
+    std::fs::create_dir_all(root.join("empty")).unwrap();
+    std::fs::write(root.join("one.txt"), vec![0u8; 10]).unwrap();
+    std::fs::write(root.join("a").join("two.txt"), vec![0u8; 200]).unwrap();
+    std::fs::write(nested.join("three.txt"), vec![0u8; 3000]).unwrap();
+
+    // A symlink back to the root would loop forever if it were followed
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(&root, nested.join("loop")).unwrap();
+
+    assert_eq!(disk_usage_iter(&root), 3210);
+    // A single file is its own total
+    assert_eq!(disk_usage_iter(&root.join("one.txt")), 10);
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
 
 // Sum of array of integers to n indexes in O(n) time using linear recursion
 // Iterative implementation (so easy, so intuitive)
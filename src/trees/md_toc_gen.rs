@@ -88,6 +88,14 @@ Example:
 Methods:
  - fn add_child(&mut self, ancestor: Pos<T>, node: Pos<T>)
  - fn children(&self, node: Pos<Heading>) -> Option<&Vec<Pos<Heading>>>
+ - fn children_iter(&self, node: Pos<T>) -> impl Iterator<Item = Pos<T>>
+ - fn size(&self) -> usize
+ - fn non_empty_size(&self) -> usize
+ - fn preorder(&self) -> Vec<Pos<T>>
+ - fn leaves(&self) -> impl Iterator<Item = Pos<T>>
+ - fn into_preorder_vec(self) -> Vec<T>
+ - fn to_flat(&self) -> Vec<(Option<usize>, Option<T>)>
+ - fn from_flat(flat: Vec<(Option<usize>, Option<T>)>) -> GenTree<T>
  - fn get(&self, node: Pos<Heading>) -> Option<&Heading>
  - fn parent(&self, node: Pos<Heading>) -> Pos<Heading>
  - fn is_root(&self, node: &Pos<T>) -> bool
@@ -97,7 +105,9 @@ Associated & Utility Functions:
  - fn simple_print(title: &String, headings: &Vec<Heading>)
  - fn parse(root: &Path) -> (String, Vec<Heading>)
  - fn construct(data: &Vec<Heading>) -> Tree<Heading>
+ - fn construct_from_levels<T>(data: Vec<(usize, T)>) -> GenTree<T>
  - fn pretty_print(name: &str, position: &Pos<Heading>)
+ - fn print_to_depth(name: &str, position: &Pos<Heading>, max_depth: Option<usize>)
  - fn preorder(position: &Pos<Heading>, prefix: &str)
  - fn navigator(path: &Path)
 */
@@ -206,6 +216,110 @@ impl<T> GenTree<T> {
         }
     }
 
+    /** Lazily yields the positions of a node's children without cloning
+    them into a new Vec like children() does */
+    pub fn children_iter(&self, node: Pos<T>) -> impl Iterator<Item = Pos<T>> + '_ {
+        let children: &[Pos<T>] = if let Some(n) = node {
+            unsafe { &(*n).children }
+        } else {
+            &[]
+        };
+        children.iter().copied()
+    }
+
+    /** Returns the number of nodes in the tree, including the root and any
+    placeholder nodes, in O(1) time */
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /** Traverses the tree once to count only the nodes that contain data in O(n) time;
+    The root and the placeholders built by construct() and construct_from_levels()
+    are empty, so they're left out */
+    pub fn non_empty_size(&self) -> usize {
+        self.preorder()
+            .iter()
+            .filter(|position| self.get(position).is_some())
+            .count()
+    }
+
+    /** Returns every position in preorder in O(n) time; Uses an explicit
+    Vec-based stack instead of recursion so very deep trees can't overflow
+    the call stack */
+    pub fn preorder(&self) -> Vec<Pos<T>> {
+        let mut positions = Vec::with_capacity(self.size);
+        let mut stack: Vec<Pos<T>> = vec![self.root];
+        while let Some(position) = stack.pop() {
+            positions.push(position);
+            // Pushes children in reverse so the first child is visited next
+            let children: Vec<Pos<T>> = self.children_iter(position).collect();
+            stack.extend(children.into_iter().rev());
+        }
+        positions
+    }
+
+    /** Yields the positions of every node with no children, in preorder;
+    Collects the traversal up front in O(n) time and filters it lazily */
+    pub fn leaves(&self) -> impl Iterator<Item = Pos<T>> + '_ {
+        self.preorder()
+            .into_iter()
+            .filter(move |position| self.is_leaf(*position))
+    }
+
+    /** Consumes the tree and returns its data in preorder in O(n) time;
+    Empty placeholder nodes (like the None root and gaps from construct_from_levels())
+    are skipped. The data is moved out of each node first, so when the tree
+    drops at the end of the function the destructor frees only empty nodes */
+    pub fn into_preorder_vec(self) -> Vec<T> {
+        self.preorder()
+            .into_iter()
+            .filter_map(|position| position.and_then(|p| unsafe { (*p).data.take() }))
+            .collect()
+    }
+
+    /** Flattens the tree into (parent index, data) pairs in O(n) time; Nodes are
+    numbered by their preorder() position, so the root is entry 0 with no parent
+    and every parent comes before its children. Data stays an Option because the
+    root and level-skip placeholders are empty */
+    pub fn to_flat(&self) -> Vec<(Option<usize>, Option<T>)>
+    where
+        T: Clone,
+    {
+        let mut flat = Vec::with_capacity(self.size);
+        // Carries each position's parent index through the same stack walk as preorder()
+        let mut stack: Vec<(Pos<T>, Option<usize>)> = vec![(self.root, None)];
+        while let Some((position, parent)) = stack.pop() {
+            let index = flat.len();
+            flat.push((parent, Node::get(position).cloned()));
+            let children: Vec<Pos<T>> = self.children_iter(position).collect();
+            stack.extend(children.into_iter().rev().map(|c| (c, Some(index))));
+        }
+        flat
+    }
+
+    /** Rebuilds a tree from the (parent index, data) pairs made by to_flat() in
+    O(n) time; Panics if the first entry has a parent or if any later entry
+    doesn't point to an earlier one */
+    pub fn from_flat(flat: Vec<(Option<usize>, Option<T>)>) -> GenTree<T> {
+        let mut entries = flat.into_iter();
+        let (parent, data) = entries.next().expect("Flat trees need a root entry");
+        assert!(parent.is_none(), "The first entry is the root and has no parent");
+        let root: Pos<T> = Some(Box::into_raw(Node::build(data)));
+        let mut tree = GenTree { root, size: 1 };
+
+        // Positions by flat index, so each child can find its parent
+        let mut positions: Vec<Pos<T>> = vec![root];
+        for (index, (parent, data)) in entries.enumerate() {
+            let parent = parent
+                .filter(|p| *p <= index)
+                .expect("Every non-root entry needs an earlier parent");
+            let node: Pos<T> = Some(Box::into_raw(Node::build(data)));
+            tree.add_child(positions[parent], node);
+            positions.push(node);
+        }
+        tree
+    }
+
 }
 
     // Associated and utility functions
@@ -274,55 +388,66 @@ impl<T> GenTree<T> {
         (doc_title, headings)
     }
 
-    /** Constructs a tree of Heading types */
+    /** Constructs a tree of Heading types; The root and any placeholders for
+    skipped levels are empty (None) and get printed as "[]" */
     pub fn construct(data: &Vec<Heading>) -> GenTree<Heading> {
-        // Instantiates a Tree with a generic root and traversal positioning
-        let mut tree: GenTree<Heading> = new();
+        let levels: Vec<(usize, Heading)> = data.iter().map(|e| (e.level, e.clone())).collect();
+        construct_from_levels(levels)
+    }
+
+    /** Constructs a tree from arbitrary data paired with a level using the same
+    algorithm as construct(); The root sits at level 0 and multi-generational
+    skips get empty (None) placeholder nodes. Panics if any entry is at level 0,
+    since the root is the only node at that level */
+    pub fn construct_from_levels<T>(data: Vec<(usize, T)>) -> GenTree<T> {
+        assert!(
+            data.iter().all(|(level, _)| *level >= 1),
+            "Levels start at 1; Level 0 is reserved for the root"
+        );
+        let root: Pos<T> = Some(Box::into_raw(Node::build(None))); // Placeholder
+        let mut tree = GenTree { root, size: 1 };
+
         // TODO: Make this a dynamic argument
         let mut level_cursor = 0; // Astro content starts at H2, skipping H1 
-        let mut position_cursor: Pos<Heading> = tree.root;
+        let mut position_cursor: Pos<T> = tree.root;
 
         // Constructs tree from Vec<T>
-        for e in data {
-            // Creates a position from a cloned list entry
-            let node: Pos<Heading> = Some(Box::into_raw(Node::build(Some(e.clone()))));
+        for (level, e) in data {
+            // Creates a position from the list entry
+            let node: Pos<T> = Some(Box::into_raw(Node::build(Some(e))));
 
             // Case: Adds a child to the current parent and sets level cursor
-            if e.level == level_cursor + 1 {
+            if level == level_cursor + 1 {
                 tree.add_child(position_cursor, node);
-                let data = tree.get(&node).unwrap();
-                level_cursor = data.level;
+                level_cursor = level;
             }
             // Case: Adds a child with multi-generational skips with empty nodes
-            else if e.level > level_cursor + 1 {
-                let diff = e.level - level_cursor;
+            else if level > level_cursor + 1 {
+                let diff = level - level_cursor;
                 for _ in 1..diff {
-                    let heading = Heading::new("[]".to_string(), 0);
-                    let placeholder: Pos<Heading> = Some(Box::into_raw(Node::build(Some(heading))));
-                    tree.add_child(position_cursor, placeholder);
-                    position_cursor = placeholder;
+                    let empty: Pos<T> = Some(Box::into_raw(Node::build(None)));
+                    tree.add_child(position_cursor, empty);
+                    position_cursor = empty;
                     level_cursor += 1;
                 }
                 tree.add_child(position_cursor, node);
-                let data = tree.get(&node).unwrap();
-                level_cursor = data.level;
+                level_cursor = level;
             }
             // Case: Adds sibling to current parent
-            else if e.level == level_cursor {
+            else if level == level_cursor {
                 tree.add_child(tree.parent(position_cursor).expect("No parent"), node);
             }
             // Case: Adds a child to the appropriate ancestor,
             // ensuring proper generational skips
             else {
-                let diff = level_cursor - e.level;
+                let diff = level_cursor - level;
                 position_cursor = tree.parent(position_cursor).expect("No parent");
                 for _ in 0..diff {
                     position_cursor = tree.parent(position_cursor).expect("No parent");
                     level_cursor -= 1;
                 }
                 tree.add_child(position_cursor, node);
-                let data = tree.get(&node).unwrap();
-                level_cursor = data.level;
+                level_cursor = level;
             }
 
             // Updates the most recent addition
@@ -342,10 +467,9 @@ impl<T> GenTree<T> {
     /** Represents a strict preorder traversal that prints the nodes */
     fn preorder_strict(position: &Pos<Heading>, prefix: &str) {
         if let Some(p) = position {
-            // Visits the current node, prints all but ROOT
-            let node = Node::get(Some(*p));
-            if node.unwrap().title != "ROOT".to_string() {
-                println!("    {}{}", prefix, node.unwrap().title);
+            // Visits the current node, prints all but the (empty) root
+            if unsafe { (*(*p)).parent.is_some() } {
+                println!("    {}{}", prefix, heading_title(Some(*p)));
             }
     
             // Gets the node's children
@@ -360,37 +484,66 @@ impl<T> GenTree<T> {
 
     /** Serves as a wrapper for a modified preorder traversal function */
     pub fn pretty_print(name: &str, position: &Pos<Heading>) {
-        //println!("\t[] {name}\n\t│");
-        println!("📄 {}\n\t│", name);
-        preorder_mod(position, "");
-        println!("");
+        print_to_depth(name, position, None);
+    }
+
+    /** Pretty-prints the tree, but stops descending past max_depth (where the
+    root is depth 0) and marks each truncated subtree with an ellipsis */
+    pub fn print_to_depth(name: &str, position: &Pos<Heading>, max_depth: Option<usize>) {
+        println!("{}", render(name, position, max_depth));
+    }
+
+    /** Renders the pretty-printed tree to a String */
+    fn render(name: &str, position: &Pos<Heading>, max_depth: Option<usize>) -> String {
+        let mut out = format!("📄 {}\n\t│\n", name);
+        preorder_mod(position, "", 0, max_depth, &mut out);
+        out
     }
 
-    /** Traverse the tree recursively, printing each node's title and children */
-    fn preorder_mod(position: &Pos<Heading>, prefix: &str) {
+    /** Traverse the tree recursively, writing each node's title and children */
+    fn preorder_mod(
+        position: &Pos<Heading>,
+        prefix: &str,
+        depth: usize,
+        max_depth: Option<usize>,
+        out: &mut String,
+    ) {
         // Checks that the position (node) exists
         if let Some(p) = position {
             // Visit the node at the referenced position
             let children: &Vec<Pos<Heading>> = unsafe { (*(*p)).children.as_ref() };
             let mut index = children.len();
 
+            // Marks the truncated subtree instead of descending past the limit
+            if max_depth.is_some_and(|max| depth >= max) {
+                if index > 0 {
+                    out.push_str(&format!("\t{}└── ...\n", prefix));
+                }
+                return;
+            }
+
             // Recursively visit each child
             for e in children {
-                let node = Node::get(*e).unwrap();
+                let title = heading_title(*e);
                 index -= 1;
                 if index == 0 {
-                    println!("\t{}└── {}", prefix, node.title);
-                    preorder_mod(e, &format!("{}    ", prefix));
+                    out.push_str(&format!("\t{}└── {}\n", prefix, title));
+                    preorder_mod(e, &format!("{}    ", prefix), depth + 1, max_depth, out);
                 } else {
-                    println!("\t{}├── {}", prefix, node.title);
-                    preorder_mod(e, &format!("{}│   ", prefix));
+                    out.push_str(&format!("\t{}├── {}\n", prefix, title));
+                    preorder_mod(e, &format!("{}│   ", prefix), depth + 1, max_depth, out);
                 }
             }
         } else {
-            println!("Not a valid position")
+            out.push_str("Not a valid position\n")
         }
     }
 
+    /** Returns a node's heading title, or "[]" for empty placeholder nodes */
+    fn heading_title<'a>(position: Pos<Heading>) -> &'a str {
+        Node::get(position).map_or("[]", |h| h.title.as_str())
+    }
+
     /** This function chains the module's utility functions to pretty-print
     a table of contents for each Markdown file in the specified directory */
     pub fn navigator(path: &Path) {
@@ -409,31 +562,16 @@ impl<T> GenTree<T> {
     }
 
     impl<T> Drop for GenTree<T> {
+        /** Iterative tree destructor; Uses the same explicit stack approach as
+        preorder() so dropping a very deep tree can't overflow the call stack */
         fn drop(&mut self) {
-            /** Recursive tree destructor */
-            // TODO: Update implementation with NonNull
-            // to avoid null pointer dereference check
-            unsafe fn drop_node_recursive<T>(node_ptr: *mut Node<T>) {
-                // Avoids a null pointer dereference
-                if node_ptr.is_null() {
-                    return;
-                }
-    
-                // Dereference the pointer and process its children
-                let node = &mut *node_ptr;
-                for &child_ptr in node.children.iter() {
-                    if let Some(child_ptr) = child_ptr {
-                        drop_node_recursive(child_ptr);
-                    }
-                }
-    
-                // Deallocate the current node
-                let _ = Box::from_raw(node_ptr);
-            }
-    
-            unsafe {
-                if let Some(root_ptr) = self.root {
-                    drop_node_recursive(root_ptr);
+            let mut stack: Vec<Pos<T>> = vec![self.root];
+            while let Some(position) = stack.pop() {
+                if let Some(node_ptr) = position {
+                    // Reclaims ownership of the node, queues its children,
+                    // and deallocates it when the Box goes out of scope
+                    let mut node = unsafe { Box::from_raw(node_ptr) };
+                    stack.append(&mut node.children);
                 }
             }
         }
@@ -495,6 +633,256 @@ fn basic_function_test() {
     }
 }
 
+#[test]
+fn construct_from_levels_test() {
+    // Builds this tree from a hand-written level list
+    //  [] 
+    //  ├── a
+    //  │   ├── b
+    //  │   └── c
+    //  │       └── []
+    //  │           └── d
+    //  └── e
+    let levels = vec![(1, 'a'), (2, 'b'), (2, 'c'), (4, 'd'), (1, 'e')];
+    let tree: GenTree<char> = construct_from_levels(levels);
+    let data = |p: Pos<char>| tree.get(&p).copied();
+
+    // Root is an empty placeholder with two children
+    assert_eq!(data(tree.root), None);
+    let root_children = tree.children(tree.root).unwrap();
+    let top: Vec<Option<char>> = root_children.iter().map(|p| data(*p)).collect();
+    assert_eq!(top, vec![Some('a'), Some('e')]);
+
+    // Siblings share a parent
+    let a = root_children[0];
+    let a_children = tree.children(a).unwrap();
+    let mid: Vec<Option<char>> = a_children.iter().map(|p| data(*p)).collect();
+    assert_eq!(mid, vec![Some('b'), Some('c')]);
+    assert!(tree.is_leaf(a_children[0]));
+
+    // The skipped level is filled with an empty placeholder
+    let c_children = tree.children(a_children[1]).unwrap();
+    assert_eq!(c_children.len(), 1);
+    assert_eq!(data(c_children[0]), None);
+    let placeholder_children = tree.children(c_children[0]).unwrap();
+    assert_eq!(data(placeholder_children[0]), Some('d'));
+    assert_eq!(tree.depth(placeholder_children[0]), Some(5));
+
+    // Stepping back up multiple levels attaches to the right ancestor
+    assert_eq!(tree.parent(root_children[1]), Some(tree.root));
+    assert_eq!(tree.size, 7);
+}
+
+#[test]
+#[should_panic(expected = "Levels start at 1; Level 0 is reserved for the root")]
+fn construct_from_levels_root_level_test() {
+    // A level 0 entry would have to be a sibling of the root
+    construct_from_levels(vec![(1, 'a'), (0, 'b')]);
+}
+
+#[test]
+fn children_iter_test() {
+    let levels = vec![(1, 'a'), (1, 'b'), (1, 'c'), (2, 'd')];
+    let tree: GenTree<char> = construct_from_levels(levels);
+
+    // Yields the same positions as the Vec-returning method
+    let lazy: Vec<Pos<char>> = tree.children_iter(tree.root).collect();
+    assert_eq!(Some(lazy), tree.children(tree.root));
+
+    // Short-circuits on the first match without visiting later children
+    let mut visited = 0;
+    let b = tree.children_iter(tree.root).find(|p| {
+        visited += 1;
+        tree.get(p) == Some(&'b')
+    });
+    assert_eq!(visited, 2);
+    assert_eq!(tree.get(&b.unwrap()), Some(&'b'));
+
+    // Leaves and invalid positions yield nothing
+    let c = tree.children_iter(tree.root).last().unwrap();
+    assert_eq!(tree.children_iter(c).count(), 1);
+    let d = tree.children_iter(c).next().unwrap();
+    assert_eq!(tree.children_iter(d).next(), None);
+    assert_eq!(tree.children_iter(None).next(), None);
+}
+
+// Parses the mock data's location tree into (level, title) pairs for the tests below
+#[cfg(test)]
+fn mock_levels() -> Vec<(usize, String)> {
+    let (_, headings) = parse(Path::new("src/trees/mock_data.md"));
+    headings.into_iter().map(|h| (h.level, h.title)).collect()
+}
+
+#[test]
+fn size_test() {
+    // Builds the mock data's location tree from its levels, which adds
+    // an empty level 1 placeholder under the root and two empty
+    // placeholders between Bolivia (H3) and the Puertas (H6)
+    let levels = mock_levels();
+    assert_eq!(levels.len(), 12);
+    let tree: GenTree<String> = construct_from_levels(levels);
+    assert_eq!(tree.size(), 16);
+    assert_eq!(tree.non_empty_size(), 12);
+
+    // The Heading tree has the same shape, and its root and placeholders are empty too
+    let (_, headings) = parse(Path::new("src/trees/mock_data.md"));
+    let tree = construct(&headings);
+    assert_eq!(tree.size(), 16);
+    assert_eq!(tree.non_empty_size(), 12);
+
+    // An empty tree is just the root
+    let tree: GenTree<String> = construct_from_levels(Vec::new());
+    assert_eq!(tree.size(), 1);
+    assert_eq!(tree.non_empty_size(), 0);
+}
+
+#[test]
+fn print_to_depth_test() {
+    let (title, headings) = parse(Path::new("src/trees/mock_data.md"));
+    let tree = construct(&headings);
+
+    // Depth 2 reaches the H2s under the level 1 placeholder and no further
+    let rendered = render(&title, &tree.root, Some(2));
+    assert_eq!(
+        rendered,
+        "📄 Location Tree Test\n\t│\n\
+        \t└── []\n\
+        \t    ├── Landlocked\n\
+        \t    │   └── ...\n\
+        \t    └── Islands\n\
+        \t        └── ...\n"
+    );
+    assert!(!rendered.contains("Switzerland"));
+    assert!(!rendered.contains("Australia"));
+
+    // Depth 0 prints only the root
+    let rendered = render(&title, &tree.root, Some(0));
+    assert_eq!(rendered, "📄 Location Tree Test\n\t│\n\t└── ...\n");
+
+    // No limit prints every node without truncation
+    let rendered = render(&title, &tree.root, None);
+    assert!(rendered.contains("Cathédrale Saint-Pierre"));
+    assert!(rendered.contains("Puerta de la Luna"));
+    assert!(!rendered.contains("..."));
+}
+
+#[test]
+fn leaves_test() {
+    use std::collections::HashSet;
+    // The location tree's leaves are spread across several depths,
+    // and none of its empty placeholders are leaves
+    let levels = mock_levels();
+    let tree: GenTree<String> = construct_from_levels(levels);
+    let leaves: HashSet<String> = tree
+        .leaves()
+        .map(|p| tree.get(&p).expect("Leaves contain data").clone())
+        .collect();
+    let expected: HashSet<String> = [
+        "Cathédrale Saint-Pierre",
+        "Puerta del Sol",
+        "Puerta de la Luna",
+        "Australia",
+        "Fresh Water",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    assert_eq!(leaves, expected);
+    assert_eq!(tree.leaves().count(), 5);
+
+    // A lone root is its own leaf
+    let tree: GenTree<String> = construct_from_levels(Vec::new());
+    let leaves: Vec<Pos<String>> = tree.leaves().collect();
+    assert_eq!(leaves, vec![tree.root]);
+}
+
+#[test]
+fn into_preorder_vec_test() {
+    use std::rc::Rc;
+    // Matches the data in preorder() order and skips the three empty placeholders
+    let levels = mock_levels();
+    let tree: GenTree<String> = construct_from_levels(levels.clone());
+    let titles = tree.into_preorder_vec();
+    assert_eq!(titles.len(), 12);
+    assert_eq!(titles[0], "Landlocked");
+    assert_eq!(titles[4], "Cathédrale Saint-Pierre");
+    // Level-based construction keeps document order, which is also preorder
+    let expected: Vec<String> = levels.into_iter().map(|(_, t)| t).collect();
+    assert_eq!(titles, expected);
+
+    // Every value is moved out exactly once; The Rc count shows nothing
+    // is still held by the dropped tree and nothing was dropped twice
+    let tracker = Rc::new(());
+    let tree: GenTree<Rc<()>> =
+        construct_from_levels((1..=5).map(|l| (l % 3 + 1, Rc::clone(&tracker))).collect());
+    assert_eq!(Rc::strong_count(&tracker), 6);
+    let values = tree.into_preorder_vec();
+    assert_eq!(values.len(), 5);
+    assert_eq!(Rc::strong_count(&tracker), 6);
+    drop(values);
+    assert_eq!(Rc::strong_count(&tracker), 1);
+}
+
+#[test]
+fn flat_test() {
+    // The root and placeholders are empty entries, and every parent comes first
+    let tree: GenTree<String> = construct_from_levels(mock_levels());
+    let flat = tree.to_flat();
+    assert_eq!(flat.len(), tree.size());
+    assert_eq!(flat[0], (None, None));
+    assert_eq!(flat[1], (Some(0), None));
+    assert_eq!(flat[2], (Some(1), Some("Landlocked".to_string())));
+    assert!(flat.iter().enumerate().skip(1).all(|(i, (p, _))| p.is_some_and(|p| p < i)));
+
+    // Round trips to the same shape and data in preorder
+    let rebuilt = GenTree::from_flat(flat.clone());
+    assert_eq!(rebuilt.size(), tree.size());
+    assert_eq!(rebuilt.to_flat(), flat);
+    let depths = |t: &GenTree<String>| -> Vec<Option<usize>> {
+        t.preorder().into_iter().map(|p| t.depth(p)).collect()
+    };
+    assert_eq!(depths(&rebuilt), depths(&tree));
+    assert_eq!(rebuilt.into_preorder_vec(), tree.into_preorder_vec());
+
+    // A lone root survives the trip too
+    let lone = GenTree::from_flat(vec![(None, Some('a'))]);
+    assert_eq!(lone.to_flat(), vec![(None, Some('a'))]);
+}
+
+#[test]
+#[should_panic(expected = "Every non-root entry needs an earlier parent")]
+fn from_flat_forward_parent_test() {
+    // A child can't point at an entry that hasn't been built yet
+    GenTree::from_flat(vec![(None, None), (Some(2), Some('a')), (Some(0), Some('b'))]);
+}
+
+#[test]
+fn preorder_test() {
+    // Visits nodes in the same order as the recursive printer
+    let (title, headings) = parse(Path::new("src/trees/mock_data.md"));
+    let tree = construct(&headings);
+    let iterative: Vec<String> = tree
+        .preorder()
+        .iter()
+        .skip(1) // The printer skips the root
+        .map(|p| heading_title(*p).to_string())
+        .collect();
+    let recursive: Vec<String> = render(&title, &tree.root, None)
+        .lines()
+        .skip(2) // Skips the document title lines
+        .map(|line| line.split("── ").nth(1).unwrap().to_string())
+        .collect();
+    assert_eq!(iterative, recursive);
+
+    // Traverses and then drops a chain far deeper than the call stack could recurse
+    let depth = 200_000;
+    let tree: GenTree<usize> = construct_from_levels((1..=depth).map(|l| (l, l)).collect());
+    let positions = tree.preorder();
+    assert_eq!(positions.len(), depth + 1);
+    assert_eq!(tree.get(positions.last().unwrap()), Some(&depth));
+    assert_eq!(tree.non_empty_size(), depth);
+}
+
 #[test]
 /** Creates this tree to test properties
     [] Lorem Ipsum Test 